@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use std::sync::Arc;
 
 use anyhow::{Result, bail};
@@ -11,10 +11,295 @@ use paraseq::parallel::{
 };
 use parking_lot::Mutex;
 
-type Patterns = Vec<Finder<'static>>;
+/// A fixed pattern paired with its reverse complement, when `--both-strands`
+/// search is enabled.
+type Pattern = (PatternMatcher, Option<PatternMatcher>);
+type Patterns = Vec<Pattern>;
+
+/// A single fixed-pattern search engine: exact (`memchr`) or mismatch-tolerant
+/// (bit-parallel edit distance), selected by `-k/--max-errors`.
+#[derive(Clone)]
+enum PatternMatcher {
+    Exact(Finder<'static>),
+    Fuzzy(FuzzyMatcher),
+}
+impl PatternMatcher {
+    fn new(pattern: &[u8], max_errors: usize) -> Self {
+        if max_errors > 0 {
+            Self::Fuzzy(FuzzyMatcher::new(pattern, max_errors))
+        } else {
+            Self::Exact(Finder::new(pattern).into_owned())
+        }
+    }
+
+    fn is_match(&self, seq: &[u8]) -> bool {
+        match self {
+            Self::Exact(finder) => finder.find(seq).is_some(),
+            Self::Fuzzy(matcher) => matcher.is_match(seq),
+        }
+    }
+
+    /// Append the byte ranges of hits of this matcher in `seq`: every
+    /// non-overlapping hit for an exact matcher, only the first hit for a
+    /// fuzzy matcher.
+    fn collect_spans(&self, seq: &[u8], spans: &mut Vec<(usize, usize)>) {
+        match self {
+            Self::Exact(finder) => {
+                let mut offset = 0;
+                while offset <= seq.len() {
+                    let Some(hit) = finder.find(&seq[offset..]) else {
+                        break;
+                    };
+                    let start = offset + hit;
+                    let end = start + finder.needle().len();
+                    spans.push((start, end));
+                    offset = end.max(start + 1);
+                }
+            }
+            Self::Fuzzy(matcher) => {
+                if let Some(end) = matcher.find_end(seq) {
+                    // Approximate: the rolling DP only tracks where the match
+                    // ends, not where it started, so this assumes a substitution-only
+                    // alignment. When the true alignment has an indel the highlighted
+                    // span is off by up to `max_errors` bytes.
+                    spans.push((end.saturating_sub(matcher.pattern.len()), end));
+                }
+            }
+        }
+    }
+}
+
+/// Mismatch-tolerant pattern matcher allowing up to `max_errors` edits
+/// (insertions, deletions, substitutions) between the pattern and some
+/// substring of the text.
+///
+/// Uses Myers' bit-vector algorithm for patterns up to 64bp and falls back to
+/// a naive banded edit-distance scan for longer patterns.
+#[derive(Clone)]
+struct FuzzyMatcher {
+    /// `Peq[c]` has bit `i` set when `pattern[i] == c`, only populated when
+    /// `pattern.len() <= WORD_SIZE`.
+    peq: [u64; 256],
+    pattern: Vec<u8>,
+    max_errors: usize,
+}
+impl FuzzyMatcher {
+    const WORD_SIZE: usize = 64;
+
+    fn new(pattern: &[u8], max_errors: usize) -> Self {
+        let mut peq = [0u64; 256];
+        if pattern.len() <= Self::WORD_SIZE {
+            for (i, &c) in pattern.iter().enumerate() {
+                peq[c as usize] |= 1 << i;
+            }
+        }
+        Self {
+            peq,
+            pattern: pattern.to_vec(),
+            max_errors,
+        }
+    }
+
+    fn is_match(&self, text: &[u8]) -> bool {
+        self.find_end(text).is_some()
+    }
+
+    /// Returns the text offset at which some substring within `max_errors` of
+    /// the pattern ends, or `None` if no such substring exists.
+    fn find_end(&self, text: &[u8]) -> Option<usize> {
+        if self.pattern.is_empty() {
+            return Some(0);
+        }
+        if self.pattern.len() <= Self::WORD_SIZE {
+            self.myers_bitvector(text)
+        } else {
+            self.banded_scan(text)
+        }
+    }
+
+    /// Myers' bit-vector algorithm: maintains a rolling edit-distance score
+    /// against every end position of the text, stopping as soon as the score
+    /// drops to or below `max_errors`.
+    fn myers_bitvector(&self, text: &[u8]) -> Option<usize> {
+        let m = self.pattern.len();
+        let high_bit = 1u64 << (m - 1);
+        let mut vp: u64 = !0;
+        let mut vn: u64 = 0;
+        let mut score = m as i64;
+
+        for (i, &c) in text.iter().enumerate() {
+            let eq = self.peq[c as usize];
+            let x = eq | vn;
+            let d0 = (vp.wrapping_add(x & vp) ^ vp) | x;
+            let hn = vp & d0;
+            let hp = vn | !(vp | d0);
+
+            if hp & high_bit != 0 {
+                score += 1;
+            }
+            if hn & high_bit != 0 {
+                score -= 1;
+            }
+
+            // No forced `|1`: this is free-start substring search, so column 0
+            // of the DP must stay pinned at 0 for every row rather than growing
+            // as it would for anchored whole-string edit distance.
+            let hp = hp << 1;
+            let hn = hn << 1;
+            vp = hn | !(x | hp);
+            vn = hp & x;
+
+            if score <= self.max_errors as i64 {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    /// Naive banded scan for patterns longer than the bit-vector word size:
+    /// computes a full edit-distance DP against every window of the text
+    /// sized to the pattern plus the error budget.
+    fn banded_scan(&self, text: &[u8]) -> Option<usize> {
+        let m = self.pattern.len();
+        if text.len() + self.max_errors < m {
+            return None;
+        }
+        for start in 0..text.len() {
+            let end = (start + m + self.max_errors).min(text.len());
+            if let Some(len) =
+                Self::edit_distance_within(&self.pattern, &text[start..end], self.max_errors)
+            {
+                return Some(start + len);
+            }
+        }
+        None
+    }
+
+    /// Returns the length of the shortest prefix of `window` within
+    /// `max_errors` edits of `pattern`, or `None` if there is none.
+    fn edit_distance_within(pattern: &[u8], window: &[u8], max_errors: usize) -> Option<usize> {
+        let m = pattern.len();
+        let mut prev: Vec<usize> = (0..=m).collect();
+        for (j, &wb) in window.iter().enumerate() {
+            let mut curr = vec![0usize; m + 1];
+            curr[0] = j + 1;
+            for i in 1..=m {
+                let cost = usize::from(pattern[i - 1] != wb);
+                curr[i] = (prev[i] + 1).min(curr[i - 1] + 1).min(prev[i - 1] + cost);
+            }
+            if curr[m] <= max_errors {
+                return Some(j + 1);
+            }
+            prev = curr;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod fuzzy_matcher_tests {
+    use super::FuzzyMatcher;
+
+    #[test]
+    fn exact_substring_at_various_positions() {
+        for start in [0, 1, 10, 50] {
+            let mut text = vec![b'N'; start];
+            text.extend_from_slice(b"ACGTACGT");
+            text.extend_from_slice(b"NNNNN");
+            let matcher = FuzzyMatcher::new(b"ACGTACGT", 0);
+            assert!(matcher.is_match(&text), "failed at start={start}");
+        }
+    }
+
+    #[test]
+    fn single_substitution_within_budget() {
+        let matcher = FuzzyMatcher::new(b"ACGTACGT", 1);
+        assert!(matcher.is_match(b"NNNNACGTCCGTNNNN"));
+    }
+
+    #[test]
+    fn exceeds_error_budget_is_rejected() {
+        let matcher = FuzzyMatcher::new(b"ACGTACGT", 1);
+        assert!(!matcher.is_match(b"NNNNAGGTCCGTNNNN"));
+    }
+
+    #[test]
+    fn insertion_and_deletion_within_budget() {
+        // One base inserted into the pattern.
+        let matcher = FuzzyMatcher::new(b"ACGTACGT", 1);
+        assert!(matcher.is_match(b"NNNNACGTTACGTNNNN"));
+        // One base deleted from the pattern.
+        assert!(matcher.is_match(b"NNNNACGACGTNNNN"));
+    }
+
+    #[test]
+    fn no_match_in_unrelated_text() {
+        let matcher = FuzzyMatcher::new(b"ACGTACGT", 1);
+        assert!(!matcher.is_match(b"TTTTTTTTTTTTTTTTTTTT"));
+    }
+
+    #[test]
+    fn single_character_exact_match() {
+        let matcher = FuzzyMatcher::new(b"A", 0);
+        assert!(matcher.is_match(b"GGGGAGGGG"));
+    }
+
+    #[test]
+    fn banded_scan_fallback_for_long_patterns() {
+        let pattern = vec![b'A'; FuzzyMatcher::WORD_SIZE + 1];
+        let mut text = vec![b'G'; 20];
+        text.extend_from_slice(&pattern);
+        text.extend_from_slice(&[b'G'; 20]);
+        let matcher = FuzzyMatcher::new(&pattern, 0);
+        assert!(matcher.is_match(&text));
+    }
+}
+
 type Expressions = Vec<regex::bytes::Regex>;
 type BoxedWriter = Box<dyn Write + Send>;
 const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+/// Placeholder Phred quality (Q40) used when synthesizing a FASTQ quality
+/// line for a record that has none (e.g. a FASTA input).
+const DUMMY_QUAL: u8 = b'I';
+/// ANSI codes wrapping a highlighted match on the sequence line (`--color`).
+const COLOR_MATCH: &[u8] = b"\x1b[1;31m";
+const COLOR_RESET: &[u8] = b"\x1b[0m";
+
+/// Output record format; `--to` overrides the default of mirroring each
+/// input record's own format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    Fastq,
+    Fasta,
+}
+
+/// When to highlight matched substrings on the sequence line with ANSI color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorMode {
+    /// Colorize only when writing to a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+/// Reverse-complement a nucleotide sequence, preserving case and leaving
+/// ambiguous/non-ACGT bases untouched.
+fn revcomp(seq: &[u8]) -> Vec<u8> {
+    seq.iter()
+        .rev()
+        .map(|&b| match b {
+            b'A' => b'T',
+            b'T' => b'A',
+            b'C' => b'G',
+            b'G' => b'C',
+            b'a' => b't',
+            b't' => b'a',
+            b'c' => b'g',
+            b'g' => b'c',
+            other => other,
+        })
+        .collect()
+}
 
 #[derive(Clone)]
 struct GrepProcessor {
@@ -28,16 +313,30 @@ struct GrepProcessor {
     re2: Expressions, // in secondary
     re: Expressions,  // in either
 
+    /// Also search the reverse complement of each read
+    both_strands: bool,
+
     /// Invert the pattern selection
     invert: bool,
 
+    /// Output format override; `None` follows the format of each input record
+    output_format: Option<OutputFormat>,
+
+    /// Suppress record output; only counts matches
+    count_only: bool,
+
+    /// Highlight matched substrings of the sequence line with ANSI color
+    use_color: bool,
+
     /// Local write buffers
     local_buffer: Vec<u8>,
     local_counter: usize,
+    local_scanned: usize,
 
     /// Global values
     global_writer: Arc<Mutex<BoxedWriter>>,
     global_counter: Arc<Mutex<usize>>,
+    global_scanned: Arc<Mutex<usize>>,
 }
 impl GrepProcessor {
     #[allow(clippy::too_many_arguments)]
@@ -48,7 +347,11 @@ impl GrepProcessor {
         re1: Expressions,
         re2: Expressions,
         re: Expressions,
+        both_strands: bool,
         invert: bool,
+        output_format: Option<OutputFormat>,
+        count_only: bool,
+        use_color: bool,
         output: BoxedWriter,
     ) -> Self {
         Self {
@@ -58,11 +361,17 @@ impl GrepProcessor {
             re1,
             re2,
             re,
+            both_strands,
             invert,
+            output_format,
+            count_only,
+            use_color,
             global_writer: Arc::new(Mutex::new(output)),
             local_buffer: Vec::with_capacity(DEFAULT_BUFFER_SIZE),
             local_counter: 0,
+            local_scanned: 0,
             global_counter: Arc::new(Mutex::new(0)),
+            global_scanned: Arc::new(Mutex::new(0)),
         }
     }
 
@@ -70,66 +379,168 @@ impl GrepProcessor {
         if self.mp1.is_empty() || seq.is_empty() {
             return true;
         }
-        self.mp1.iter().all(|pat| pat.find(seq).is_some())
+        self.mp1
+            .iter()
+            .all(|(fwd, rc)| fwd.is_match(seq) || rc.as_ref().is_some_and(|rc| rc.is_match(seq)))
     }
 
     fn search_secondary(&self, seq: &[u8]) -> bool {
         if self.mp2.is_empty() || seq.is_empty() {
             return true;
         }
-        self.mp2.iter().any(|pat| pat.find(seq).is_some())
+        self.mp2
+            .iter()
+            .any(|(fwd, rc)| fwd.is_match(seq) || rc.as_ref().is_some_and(|rc| rc.is_match(seq)))
     }
 
     fn search_either(&self, r1: &[u8], r2: &[u8]) -> bool {
         if self.pat.is_empty() {
             return true;
         }
-        self.pat
-            .iter()
-            .any(|pat| pat.find(r1).is_some() || pat.find(r2).is_some())
+        self.pat.iter().any(|(fwd, rc)| {
+            fwd.is_match(r1)
+                || fwd.is_match(r2)
+                || rc.as_ref()
+                    .is_some_and(|rc| rc.is_match(r1) || rc.is_match(r2))
+        })
     }
 
-    fn regex_primary(&self, seq: &[u8]) -> bool {
+    fn regex_primary(&self, seq: &[u8], rc_seq: Option<&[u8]>) -> bool {
         if self.re1.is_empty() || seq.is_empty() {
             return true;
         }
-        self.re1.iter().any(|re| re.find(seq).is_some())
+        self.re1
+            .iter()
+            .any(|re| re.find(seq).is_some() || rc_seq.is_some_and(|rc| re.find(rc).is_some()))
     }
 
-    fn regex_secondary(&self, seq: &[u8]) -> bool {
+    fn regex_secondary(&self, seq: &[u8], rc_seq: Option<&[u8]>) -> bool {
         if self.re2.is_empty() || seq.is_empty() {
             return true;
         }
-        self.re2.iter().any(|re| re.find(seq).is_some())
+        self.re2
+            .iter()
+            .any(|re| re.find(seq).is_some() || rc_seq.is_some_and(|rc| re.find(rc).is_some()))
     }
 
-    fn regex_either(&self, r1: &[u8], r2: &[u8]) -> bool {
+    fn regex_either(&self, r1: &[u8], r2: &[u8], rc1: Option<&[u8]>, rc2: Option<&[u8]>) -> bool {
         if self.re.is_empty() {
             return true;
         }
-        self.re
-            .iter()
-            .any(|re| re.find(r1).is_some() || re.find(r2).is_some())
+        self.re.iter().any(|re| {
+            re.find(r1).is_some()
+                || re.find(r2).is_some()
+                || rc1.is_some_and(|rc| re.find(rc).is_some())
+                || rc2.is_some_and(|rc| re.find(rc).is_some())
+        })
     }
 
     pub fn pattern_match(&self, primary: &[u8], secondary: &[u8]) -> bool {
+        let rc_primary = self.both_strands.then(|| revcomp(primary));
+        let rc_secondary = self.both_strands.then(|| revcomp(secondary));
         let pred = self.search_primary(primary)
             && self.search_secondary(secondary)
             && self.search_either(primary, secondary)
-            && self.regex_primary(primary)
-            && self.regex_secondary(secondary)
-            && self.regex_either(primary, secondary);
+            && self.regex_primary(primary, rc_primary.as_deref())
+            && self.regex_secondary(secondary, rc_secondary.as_deref())
+            && self.regex_either(
+                primary,
+                secondary,
+                rc_primary.as_deref(),
+                rc_secondary.as_deref(),
+            );
         if self.invert { !pred } else { pred }
     }
 
-    pub fn write_record<Rf: Record>(&mut self, record: Rf) -> Result<()> {
-        self.local_buffer.write(b"@")?;
-        self.local_buffer.extend_from_slice(record.id());
-        self.local_buffer.write(b"\n")?;
-        self.local_buffer.extend_from_slice(record.seq());
-        self.local_buffer.write(b"\n+\n")?;
-        self.local_buffer.extend_from_slice(record.qual().unwrap());
-        self.local_buffer.write(b"\n")?;
+    /// Collect the byte ranges in `primary`/`secondary` that triggered the
+    /// match, for `--color` highlighting. Both orientations of a fixed
+    /// pattern are real literal matchers run against the unmodified read, so
+    /// their hits are highlighted directly; only the regex both-strands path
+    /// (which matches against a derived reverse-complement sequence) has no
+    /// corresponding literal substring to highlight.
+    pub fn match_spans(
+        &self,
+        primary: &[u8],
+        secondary: &[u8],
+    ) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+        let mut primary_spans = Vec::new();
+        for (fwd, rc) in self.mp1.iter().chain(&self.pat) {
+            fwd.collect_spans(primary, &mut primary_spans);
+            if let Some(rc) = rc {
+                rc.collect_spans(primary, &mut primary_spans);
+            }
+        }
+        for re in self.re1.iter().chain(&self.re) {
+            primary_spans.extend(re.find_iter(primary).map(|m| (m.start(), m.end())));
+        }
+
+        let mut secondary_spans = Vec::new();
+        for (fwd, rc) in self.mp2.iter().chain(&self.pat) {
+            fwd.collect_spans(secondary, &mut secondary_spans);
+            if let Some(rc) = rc {
+                rc.collect_spans(secondary, &mut secondary_spans);
+            }
+        }
+        for re in self.re2.iter().chain(&self.re) {
+            secondary_spans.extend(re.find_iter(secondary).map(|m| (m.start(), m.end())));
+        }
+
+        (primary_spans, secondary_spans)
+    }
+
+    /// Write the sequence line, wrapping matched spans in ANSI color codes
+    /// when `--color` is in effect.
+    fn write_seq(&mut self, seq: &[u8], spans: &[(usize, usize)]) -> Result<()> {
+        if !self.use_color || spans.is_empty() {
+            self.local_buffer.extend_from_slice(seq);
+            return Ok(());
+        }
+
+        let mut spans = spans.to_vec();
+        spans.sort_unstable_by_key(|&(start, _)| start);
+        let mut cursor = 0;
+        for (start, end) in spans {
+            let start = start.clamp(cursor, seq.len());
+            let end = end.clamp(start, seq.len());
+            self.local_buffer.extend_from_slice(&seq[cursor..start]);
+            self.local_buffer.extend_from_slice(COLOR_MATCH);
+            self.local_buffer.extend_from_slice(&seq[start..end]);
+            self.local_buffer.extend_from_slice(COLOR_RESET);
+            cursor = end;
+        }
+        self.local_buffer.extend_from_slice(&seq[cursor..]);
+        Ok(())
+    }
+
+    pub fn write_record<Rf: Record>(&mut self, record: Rf, spans: &[(usize, usize)]) -> Result<()> {
+        let qual = record.qual();
+        let as_fasta = match self.output_format {
+            Some(OutputFormat::Fasta) => true,
+            Some(OutputFormat::Fastq) => false,
+            None => qual.is_none(),
+        };
+        if as_fasta {
+            self.local_buffer.write(b">")?;
+            self.local_buffer.extend_from_slice(record.id());
+            self.local_buffer.write(b"\n")?;
+            self.write_seq(record.seq(), spans)?;
+            self.local_buffer.write(b"\n")?;
+        } else {
+            self.local_buffer.write(b"@")?;
+            self.local_buffer.extend_from_slice(record.id());
+            self.local_buffer.write(b"\n")?;
+            self.write_seq(record.seq(), spans)?;
+            self.local_buffer.write(b"\n+\n")?;
+            match qual {
+                Some(qual) => self.local_buffer.extend_from_slice(qual),
+                // No quality scores to carry over (e.g. a FASTA record): synthesize a
+                // dummy high-quality line so the output is still valid FASTQ.
+                None => self
+                    .local_buffer
+                    .resize(self.local_buffer.len() + record.seq().len(), DUMMY_QUAL),
+            }
+            self.local_buffer.write(b"\n")?;
+        }
         Ok(())
     }
 }
@@ -138,8 +549,16 @@ impl ParallelProcessor for GrepProcessor {
         &mut self,
         record: Rf,
     ) -> paraseq::parallel::Result<()> {
+        self.local_scanned += 1;
         if self.pattern_match(record.seq(), &[]) {
-            self.write_record(record)?;
+            if !self.count_only {
+                let primary_spans = if self.use_color {
+                    self.match_spans(record.seq(), &[]).0
+                } else {
+                    Vec::new()
+                };
+                self.write_record(record, &primary_spans)?;
+            }
             self.local_counter += 1;
         }
         Ok(())
@@ -154,6 +573,8 @@ impl ParallelProcessor for GrepProcessor {
 
         *self.global_counter.lock() += self.local_counter;
         self.local_counter = 0;
+        *self.global_scanned.lock() += self.local_scanned;
+        self.local_scanned = 0;
         Ok(())
     }
 }
@@ -163,9 +584,17 @@ impl PairedParallelProcessor for GrepProcessor {
         record1: Rf,
         record2: Rf,
     ) -> paraseq::parallel::Result<()> {
+        self.local_scanned += 1;
         if self.pattern_match(record1.seq(), record2.seq()) {
-            self.write_record(record1)?;
-            self.write_record(record2)?;
+            if !self.count_only {
+                let (primary_spans, secondary_spans) = if self.use_color {
+                    self.match_spans(record1.seq(), record2.seq())
+                } else {
+                    (Vec::new(), Vec::new())
+                };
+                self.write_record(record1, &primary_spans)?;
+                self.write_record(record2, &secondary_spans)?;
+            }
             self.local_counter += 1;
         }
         Ok(())
@@ -181,31 +610,99 @@ impl PairedParallelProcessor for GrepProcessor {
 
         *self.global_counter.lock() += self.local_counter;
         self.local_counter = 0;
+        *self.global_scanned.lock() += self.local_scanned;
+        self.local_scanned = 0;
         Ok(())
     }
 }
 
-fn match_output(path: Option<String>) -> Result<BoxedWriter> {
+/// Pick a niffler compression format from the output path's extension,
+/// falling back to uncompressed output.
+fn compression_format(path: &str) -> niffler::compression::Format {
+    if path.ends_with(".gz") {
+        niffler::compression::Format::Gzip
+    } else if path.ends_with(".bz2") {
+        niffler::compression::Format::Bzip2
+    } else if path.ends_with(".zst") {
+        niffler::compression::Format::Zstd
+    } else {
+        niffler::compression::Format::No
+    }
+}
+
+fn compression_level(level: u8) -> niffler::Level {
+    match level {
+        0 | 1 => niffler::Level::One,
+        2 => niffler::Level::Two,
+        3 => niffler::Level::Three,
+        4 => niffler::Level::Four,
+        5 => niffler::Level::Five,
+        6 => niffler::Level::Six,
+        7 => niffler::Level::Seven,
+        8 => niffler::Level::Eight,
+        _ => niffler::Level::Nine,
+    }
+}
+
+/// Open the match output, transparently compressing based on the output
+/// path's extension (`.gz`, `.zst`, `.bz2`). The returned writer finalizes
+/// its compression trailer when dropped at the end of the run.
+fn match_output(path: Option<String>, compress_level: u8) -> Result<BoxedWriter> {
     if let Some(path) = path {
-        Ok(Box::new(std::fs::File::create(path)?))
+        let format = compression_format(&path);
+        let level = compression_level(compress_level);
+        Ok(niffler::send::to_path(&path, format, level)?)
     } else {
         Ok(Box::new(std::io::stdout()))
     }
 }
 
+/// Resolve `--color` against whether the output is actually going to a terminal.
+fn resolve_color(mode: ColorMode, writing_to_stdout: bool) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => writing_to_stdout && std::io::stdout().is_terminal(),
+    }
+}
+
+/// Print the post-run summary requested via `-c/--count` and `--stats`.
+fn report_summary(scanned: usize, matched: usize, unit: &str, count: bool, stats: bool) {
+    if count {
+        println!("{matched}");
+    }
+    if stats {
+        let fraction = if scanned == 0 {
+            0.0
+        } else {
+            matched as f64 / scanned as f64
+        };
+        eprintln!(
+            "{scanned} {unit} scanned, {matched} matched ({:.2}% passing)",
+            fraction * 100.0
+        );
+    }
+}
+
 fn grep_paired(
     r1_path: &str,
     r2_path: &str,
     output: Option<String>,
+    to: Option<OutputFormat>,
     num_threads: usize,
     args: &GrepArgs,
+    count: bool,
+    stats: bool,
+    compress_level: u8,
+    color: ColorMode,
 ) -> Result<()> {
     let (r1_handle, _comp) = niffler::send::from_path(r1_path)?;
     let (r2_handle, _comp) = niffler::send::from_path(r2_path)?;
 
     let r1_reader = Reader::new(r1_handle);
     let r2_reader = Reader::new(r2_handle);
-    let output = match_output(output)?;
+    let use_color = resolve_color(color, output.is_none());
+    let output = match_output(output, compress_level)?;
 
     let processor = GrepProcessor::new(
         args.bytes_mp1(),
@@ -214,25 +711,39 @@ fn grep_paired(
         args.bytes_reg1(),
         args.bytes_reg2(),
         args.bytes_reg(),
+        args.both_strands,
         args.invert,
+        to,
+        count,
+        use_color,
         output,
     );
+    let global_counter = processor.global_counter.clone();
+    let global_scanned = processor.global_scanned.clone();
 
     r1_reader.process_parallel_paired(r2_reader, processor, num_threads)?;
 
+    report_summary(*global_scanned.lock(), *global_counter.lock(), "pairs", count, stats);
+
     Ok(())
 }
 
 fn grep_single(
     r1_path: &str,
     output: Option<String>,
+    to: Option<OutputFormat>,
     num_threads: usize,
     args: &GrepArgs,
+    count: bool,
+    stats: bool,
+    compress_level: u8,
+    color: ColorMode,
 ) -> Result<()> {
     let (r1_handle, _comp) = niffler::send::from_path(r1_path)?;
 
     let r1_reader = Reader::new(r1_handle);
-    let output = match_output(output)?;
+    let use_color = resolve_color(color, output.is_none());
+    let output = match_output(output, compress_level)?;
 
     let processor = GrepProcessor::new(
         args.bytes_mp1(),
@@ -241,12 +752,20 @@ fn grep_single(
         args.bytes_reg1(),
         args.bytes_reg2(),
         args.bytes_reg(),
+        args.both_strands,
         args.invert,
+        to,
+        count,
+        use_color,
         output,
     );
+    let global_counter = processor.global_counter.clone();
+    let global_scanned = processor.global_scanned.clone();
 
     r1_reader.process_parallel(processor, num_threads)?;
 
+    report_summary(*global_scanned.lock(), *global_counter.lock(), "reads", count, stats);
+
     Ok(())
 }
 
@@ -259,11 +778,26 @@ fn main() -> Result<()> {
             &args.inputs[0],
             &args.inputs[1],
             args.output,
+            args.to,
             args.threads,
             &args.grep,
+            args.count,
+            args.stats,
+            args.compress_level,
+            args.color,
         )
     } else if args.inputs.len() == 1 {
-        grep_single(&args.inputs[0], args.output, args.threads, &args.grep)
+        grep_single(
+            &args.inputs[0],
+            args.output,
+            args.to,
+            args.threads,
+            &args.grep,
+            args.count,
+            args.stats,
+            args.compress_level,
+            args.color,
+        )
     } else {
         bail!("Must provide either 1 or 2 input files")
     }
@@ -280,6 +814,26 @@ pub struct GrepCommand {
     #[clap(short = 'o', long)]
     pub output: Option<String>,
 
+    /// Output format [default: matches each input record's own format]
+    #[clap(long = "to", value_enum)]
+    pub to: Option<OutputFormat>,
+
+    /// Compression level (1-9) used when the output path implies a compressed format
+    #[clap(long, default_value_t = 6)]
+    pub compress_level: u8,
+
+    /// Suppress record output and print only the number of matching reads (or pairs)
+    #[clap(short = 'c', long)]
+    pub count: bool,
+
+    /// Print a summary (records scanned, matched, fraction passing) to stderr
+    #[clap(long)]
+    pub stats: bool,
+
+    /// Highlight matched substrings on the sequence line
+    #[clap(long, value_enum, default_value_t = ColorMode::Auto)]
+    pub color: ColorMode,
+
     /// Number of threads to use [default: 1]
     #[clap(short = 'T', long, default_value_t = 1)]
     pub threads: usize,
@@ -318,6 +872,18 @@ pub struct GrepArgs {
     /// Invert pattern criteria (like grep -v)
     #[clap(short = 'v', long)]
     pub invert: bool,
+
+    /// Also search the reverse complement of each read for fixed/regex patterns
+    #[clap(short = 'b', long = "both-strands")]
+    pub both_strands: bool,
+
+    /// Allow up to N mismatches/indels when matching fixed patterns (edit distance)
+    #[clap(short = 'k', long = "max-errors", default_value_t = 0)]
+    pub max_errors: usize,
+
+    /// Treat `-e/-E/-F` patterns as IUPAC degenerate codes, matched as case-insensitive regex
+    #[clap(long)]
+    pub iupac: bool,
 }
 impl GrepArgs {
     pub fn validate(&self) -> Result<()> {
@@ -332,43 +898,77 @@ impl GrepArgs {
         }
         Ok(())
     }
-    pub fn bytes_mp1(&self) -> Vec<Finder<'static>> {
-        self.pat1
-            .iter()
-            .map(|s| Finder::new(s.as_bytes()))
-            .map(|f| f.into_owned())
-            .collect()
+    fn bytes_finder_pair(&self, pattern: &str) -> Pattern {
+        let fwd = PatternMatcher::new(pattern.as_bytes(), self.max_errors);
+        let rc = self
+            .both_strands
+            .then(|| PatternMatcher::new(&revcomp(pattern.as_bytes()), self.max_errors));
+        (fwd, rc)
     }
-    pub fn bytes_mp2(&self) -> Vec<Finder<'static>> {
-        self.pat2
-            .iter()
-            .map(|s| Finder::new(s.as_bytes()))
-            .map(|f| f.into_owned())
-            .collect()
+    pub fn bytes_mp1(&self) -> Patterns {
+        if self.iupac {
+            return Vec::new();
+        }
+        self.pat1.iter().map(|s| self.bytes_finder_pair(s)).collect()
     }
-    pub fn bytes_pat(&self) -> Vec<Finder<'static>> {
-        self.pat2
-            .iter()
-            .map(|s| Finder::new(s.as_bytes()))
-            .map(|f| f.into_owned())
-            .collect()
+    pub fn bytes_mp2(&self) -> Patterns {
+        if self.iupac {
+            return Vec::new();
+        }
+        self.pat2.iter().map(|s| self.bytes_finder_pair(s)).collect()
+    }
+    pub fn bytes_pat(&self) -> Patterns {
+        if self.iupac {
+            return Vec::new();
+        }
+        self.pat.iter().map(|s| self.bytes_finder_pair(s)).collect()
+    }
+    fn bytes_regex(pattern: &str) -> regex::bytes::Regex {
+        regex::bytes::Regex::new(pattern)
+            .unwrap_or_else(|_| panic!("Could not build regex from pattern: {pattern}"))
     }
     pub fn bytes_reg1(&self) -> Vec<regex::bytes::Regex> {
-        self.reg1
-            .iter()
-            .map(|s| regex::bytes::Regex::new(s).expect("Could not build regex from pattern: {s}"))
-            .collect()
+        let mut exprs: Vec<_> = self.reg1.iter().map(|s| Self::bytes_regex(s)).collect();
+        if self.iupac {
+            exprs.extend(self.pat1.iter().map(|s| Self::bytes_regex(&iupac_to_regex(s))));
+        }
+        exprs
     }
     pub fn bytes_reg2(&self) -> Vec<regex::bytes::Regex> {
-        self.reg2
-            .iter()
-            .map(|s| regex::bytes::Regex::new(s).expect("Could not build regex from pattern: {s}"))
-            .collect()
+        let mut exprs: Vec<_> = self.reg2.iter().map(|s| Self::bytes_regex(s)).collect();
+        if self.iupac {
+            exprs.extend(self.pat2.iter().map(|s| Self::bytes_regex(&iupac_to_regex(s))));
+        }
+        exprs
     }
     pub fn bytes_reg(&self) -> Vec<regex::bytes::Regex> {
-        self.reg
-            .iter()
-            .map(|s| regex::bytes::Regex::new(s).expect("Could not build regex from pattern: {s}"))
-            .collect()
+        let mut exprs: Vec<_> = self.reg.iter().map(|s| Self::bytes_regex(s)).collect();
+        if self.iupac {
+            exprs.extend(self.pat.iter().map(|s| Self::bytes_regex(&iupac_to_regex(s))));
+        }
+        exprs
+    }
+}
+
+/// Expand an IUPAC degenerate-base pattern into an equivalent, case-insensitive
+/// `regex::bytes::Regex` source string.
+fn iupac_to_regex(pattern: &str) -> String {
+    let mut expr = String::from("(?i)");
+    for c in pattern.chars() {
+        match c.to_ascii_uppercase() {
+            'N' => expr.push_str("[ACGTN]"),
+            'R' => expr.push_str("[AG]"),
+            'Y' => expr.push_str("[CT]"),
+            'S' => expr.push_str("[GC]"),
+            'W' => expr.push_str("[AT]"),
+            'K' => expr.push_str("[GT]"),
+            'M' => expr.push_str("[AC]"),
+            'B' => expr.push_str("[CGT]"),
+            'D' => expr.push_str("[AGT]"),
+            'H' => expr.push_str("[ACT]"),
+            'V' => expr.push_str("[ACG]"),
+            _ => expr.push_str(&regex::escape(&c.to_string())),
+        }
     }
+    expr
 }